@@ -60,40 +60,300 @@ pub struct PathItem {
   pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+/// An HTTP method that a [PathItem] can declare an [Operation] for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+  Get,
+  Put,
+  Post,
+  Delete,
+  Options,
+  Head,
+  Patch,
+  Trace,
+}
+
+impl Method {
+  /// All methods a [PathItem] can hold an [Operation] for, in the same
+  /// order as [PathItem::iter].
+  pub const ALL: [Method; 8] = [
+    Method::Get,
+    Method::Put,
+    Method::Post,
+    Method::Delete,
+    Method::Options,
+    Method::Head,
+    Method::Patch,
+    Method::Trace,
+  ];
+
+  /// The lowercase method name used as the field name in the OpenAPI
+  /// document and returned by [PathItem::iter].
+  fn as_str(&self) -> &'static str {
+    match self {
+      Method::Get => "get",
+      Method::Put => "put",
+      Method::Post => "post",
+      Method::Delete => "delete",
+      Method::Options => "options",
+      Method::Head => "head",
+      Method::Patch => "patch",
+      Method::Trace => "trace",
+    }
+  }
+}
+
+impl std::fmt::Display for Method {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl std::str::FromStr for Method {
+  type Err = UnknownMethod;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_ascii_lowercase().as_str() {
+      "get" => Ok(Method::Get),
+      "put" => Ok(Method::Put),
+      "post" => Ok(Method::Post),
+      "delete" => Ok(Method::Delete),
+      "options" => Ok(Method::Options),
+      "head" => Ok(Method::Head),
+      "patch" => Ok(Method::Patch),
+      "trace" => Ok(Method::Trace),
+      _ => Err(UnknownMethod(s.to_string())),
+    }
+  }
+}
+
+/// Returned by [Method]'s `FromStr` implementation when given a string
+/// that isn't one of the eight HTTP methods a [PathItem] supports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownMethod(String);
+
+impl std::fmt::Display for UnknownMethod {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "unknown HTTP method: {}", self.0)
+  }
+}
+
+impl std::error::Error for UnknownMethod {}
+
 impl PathItem {
+  /// Returns the [Operation] declared for `method`, if any.
+  pub fn operation(&self, method: Method) -> Option<&Operation> {
+    match method {
+      Method::Get => self.get.as_ref(),
+      Method::Put => self.put.as_ref(),
+      Method::Post => self.post.as_ref(),
+      Method::Delete => self.delete.as_ref(),
+      Method::Options => self.options.as_ref(),
+      Method::Head => self.head.as_ref(),
+      Method::Patch => self.patch.as_ref(),
+      Method::Trace => self.trace.as_ref(),
+    }
+  }
+
+  /// Returns a mutable reference to the [Operation] declared for `method`,
+  /// if any.
+  pub fn operation_mut(&mut self, method: Method) -> Option<&mut Operation> {
+    match method {
+      Method::Get => self.get.as_mut(),
+      Method::Put => self.put.as_mut(),
+      Method::Post => self.post.as_mut(),
+      Method::Delete => self.delete.as_mut(),
+      Method::Options => self.options.as_mut(),
+      Method::Head => self.head.as_mut(),
+      Method::Patch => self.patch.as_mut(),
+      Method::Trace => self.trace.as_mut(),
+    }
+  }
+
+  /// Sets the [Operation] for `method`, replacing any existing one.
+  pub fn set_operation(&mut self, method: Method, operation: Operation) {
+    *self.operation_slot(method) = Some(operation);
+  }
+
+  /// Removes and returns the [Operation] for `method`, if any.
+  pub fn take_operation(&mut self, method: Method) -> Option<Operation> {
+    self.operation_slot(method).take()
+  }
+
+  /// Validates that every `{...}` token in `path` has a corresponding
+  /// `in: path`, `required: true` [Parameter] declared on this path item
+  /// or on one of its operations, and that every such declared parameter
+  /// appears in `path`. `path` is the templated key this [PathItem] is
+  /// registered under in [Paths].
+  ///
+  /// References in `parameters` (on the path item and on its operations)
+  /// are skipped, since resolving them requires the components they point
+  /// into; a template variable declared only through a reference is
+  /// reported as [PathValidationError::UndeclaredVariable].
+  pub fn validate_path(&self, path: &str) -> Vec<PathValidationError> {
+    let template_vars = template_variables(path);
+
+    let declared: Vec<&ParameterData> = self
+      .parameters
+      .iter()
+      .chain(self.iter().flat_map(|(_, operation)| operation.parameters.iter()))
+      .filter_map(ReferenceOr::as_item)
+      .filter_map(|parameter| match parameter {
+        Parameter::Path { parameter_data, .. } => Some(parameter_data),
+        _ => None,
+      })
+      .collect();
+
+    let mut errors = Vec::new();
+
+    for name in &template_vars {
+      if !declared.iter().any(|param| &param.name == name) {
+        errors.push(PathValidationError::UndeclaredVariable {
+          path: path.to_string(),
+          name: name.clone(),
+        });
+      }
+    }
+
+    for param in &declared {
+      if !template_vars.contains(&param.name) {
+        errors.push(PathValidationError::UnusedParameter {
+          path: path.to_string(),
+          name: param.name.clone(),
+        });
+      } else if !param.required {
+        errors.push(PathValidationError::NotRequired {
+          path: path.to_string(),
+          name: param.name.clone(),
+        });
+      }
+    }
+
+    errors
+  }
+
+  /// Computes the effective parameter list for the operation under
+  /// `method`: the path-level [PathItem::parameters], with any
+  /// operation-level parameter sharing the same name and location
+  /// overriding (not removing) its path-level counterpart, and any
+  /// genuinely new operation-level parameter appended.
+  ///
+  /// Parameters may be [ReferenceOr::Reference]s; `resolve` is given the
+  /// `$ref` string and, if it returns `Some`, the resolved name/location
+  /// is used for the override comparison. A reference `resolve` can't
+  /// resolve is kept as-is and treated as distinct from every other
+  /// parameter.
+  pub fn effective_parameters<'a>(
+    &'a self,
+    method: Method,
+    resolve: impl Fn(&str) -> Option<&'a Parameter>,
+  ) -> Vec<&'a ReferenceOr<Parameter>> {
+    let key = |param: &'a ReferenceOr<Parameter>| -> Option<ParameterKey> {
+      match param {
+        ReferenceOr::Item(parameter) => Some(parameter_key(parameter)),
+        ReferenceOr::Reference { reference } => resolve(reference).map(parameter_key),
+      }
+    };
+
+    let mut result: Vec<&'a ReferenceOr<Parameter>> = self.parameters.iter().collect();
+
+    let operation_parameters = self
+      .operation(method)
+      .map(|operation| operation.parameters.iter())
+      .into_iter()
+      .flatten();
+
+    for operation_param in operation_parameters {
+      match key(operation_param) {
+        Some(operation_key) => {
+          match result.iter().position(|existing| key(existing) == Some(operation_key.clone())) {
+            Some(index) => result[index] = operation_param,
+            None => result.push(operation_param),
+          }
+        }
+        None => result.push(operation_param),
+      }
+    }
+
+    result
+  }
+
+  fn operation_slot(&mut self, method: Method) -> &mut Option<Operation> {
+    match method {
+      Method::Get => &mut self.get,
+      Method::Put => &mut self.put,
+      Method::Post => &mut self.post,
+      Method::Delete => &mut self.delete,
+      Method::Options => &mut self.options,
+      Method::Head => &mut self.head,
+      Method::Patch => &mut self.patch,
+      Method::Trace => &mut self.trace,
+    }
+  }
+
   /// Returns an iterator of references to the [Operation]s in the [PathItem].
   pub fn iter(&self) -> impl Iterator<Item = (&str, &'_ Operation)> {
-    vec![
-      ("get", &self.get),
-      ("put", &self.put),
-      ("post", &self.post),
-      ("delete", &self.delete),
-      ("options", &self.options),
-      ("head", &self.head),
-      ("patch", &self.patch),
-      ("trace", &self.trace),
-    ]
-    .into_iter()
-    .filter_map(|(method, maybe_op)| maybe_op.as_ref().map(|op| (method, op)))
+    Method::ALL
+      .iter()
+      .filter_map(move |method| self.operation(*method).map(|op| (method.as_str(), op)))
   }
 
   /// Returns an iterator of mutable references to the [Operation]s in the [PathItem].
   pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &'_ mut Operation)> {
-    vec![
-      ("get", &mut self.get),
-      ("put", &mut self.put),
-      ("post", &mut self.post),
-      ("delete", &mut self.delete),
-      ("options", &mut self.options),
-      ("head", &mut self.head),
-      ("patch", &mut self.patch),
-      ("trace", &mut self.trace),
-    ]
-    .into_iter()
-    .filter_map(|(method, maybe_op)| maybe_op.as_mut().map(|op| (method, op)))
+    let operations = [
+      &mut self.get,
+      &mut self.put,
+      &mut self.post,
+      &mut self.delete,
+      &mut self.options,
+      &mut self.head,
+      &mut self.patch,
+      &mut self.trace,
+    ];
+    Method::ALL
+      .iter()
+      .map(Method::as_str)
+      .zip(operations)
+      .filter_map(|(method, maybe_op)| maybe_op.as_mut().map(|op| (method, op)))
+  }
+
+  /// Like [PathItem::iter], but skips any operation for which `pred`
+  /// returns `false`.
+  pub fn iter_filtered<'a>(
+    &'a self,
+    pred: impl Fn(&str, &'a Operation) -> bool + 'a,
+  ) -> impl Iterator<Item = (&'a str, &'a Operation)> {
+    self.iter().filter(move |(method, operation)| pred(method, operation))
+  }
+
+  /// Like [PathItem::iter], but skips operations marked with a truthy
+  /// `x-internal` vendor extension. Use
+  /// [PathItem::iter_public_with_key] to honor a differently named
+  /// extension.
+  pub fn iter_public(&self) -> impl Iterator<Item = (&str, &'_ Operation)> {
+    self.iter_public_with_key("x-internal")
+  }
+
+  /// Like [PathItem::iter_public], but checks `extension_key` instead of
+  /// the default `x-internal`.
+  pub fn iter_public_with_key<'a>(
+    &'a self,
+    extension_key: &'a str,
+  ) -> impl Iterator<Item = (&'a str, &'a Operation)> {
+    self.iter_filtered(move |_, operation| !is_truthy_extension(operation, extension_key))
   }
 }
 
+/// Returns whether `operation` carries a truthy value for the vendor
+/// extension `key`, e.g. `x-internal: true`.
+fn is_truthy_extension(operation: &Operation, key: &str) -> bool {
+  operation
+    .extensions
+    .get(key)
+    .and_then(serde_json::Value::as_bool)
+    .unwrap_or(false)
+}
+
 impl IntoIterator for PathItem {
   type Item = (&'static str, Operation);
 
@@ -101,20 +361,16 @@ impl IntoIterator for PathItem {
 
   /// Returns an iterator of the [Operation]s in the [PathItem].
   fn into_iter(self) -> Self::IntoIter {
-    vec![
-      ("get", self.get),
-      ("put", self.put),
-      ("post", self.post),
-      ("delete", self.delete),
-      ("options", self.options),
-      ("head", self.head),
-      ("patch", self.patch),
-      ("trace", self.trace),
-    ]
-    .into_iter()
-    .filter_map(|(method, maybe_op)| maybe_op.map(|op| (method, op)))
-    .collect::<Vec<_>>()
-    .into_iter()
+    let operations = [
+      self.get, self.put, self.post, self.delete, self.options, self.head, self.patch, self.trace,
+    ];
+    Method::ALL
+      .iter()
+      .map(Method::as_str)
+      .zip(operations)
+      .filter_map(|(method, maybe_op)| maybe_op.map(|op| (method, op)))
+      .collect::<Vec<_>>()
+      .into_iter()
   }
 }
 
@@ -137,6 +393,78 @@ impl Paths {
   pub fn iter(&self) -> indexmap::map::Iter<String, ReferenceOr<PathItem>> {
     self.paths.iter()
   }
+
+  /// Runs [PathItem::validate_path] over every entry, skipping templates
+  /// that are themselves a `$ref` rather than an inline [PathItem].
+  pub fn validate(&self) -> Vec<PathValidationError> {
+    self
+      .paths
+      .iter()
+      .filter_map(|(path, item)| item.as_item().map(|item| (path, item)))
+      .flat_map(|(path, item)| item.validate_path(path))
+      .collect()
+  }
+}
+
+/// An error found by [Paths::validate] or [PathItem::validate_path]
+/// describing a mismatch between a templated path and its declared
+/// path parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathValidationError {
+  /// A `{...}` token appears in the path template but no `Parameter`
+  /// with `in: path` and that name is declared on the path item or any
+  /// of its operations.
+  UndeclaredVariable { path: String, name: String },
+  /// A declared path parameter's name does not appear as a `{...}`
+  /// token in the path template.
+  UnusedParameter { path: String, name: String },
+  /// A parameter whose name matches a template variable is declared but
+  /// not marked `required: true`, as the spec mandates for path params.
+  NotRequired { path: String, name: String },
+}
+
+/// The (name, location) identity used by [PathItem::effective_parameters]
+/// to decide whether an operation-level parameter overrides a path-level
+/// one, per the OpenAPI rule that a parameter is uniquely identified by
+/// its name and location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParameterKey(String, ParameterLocation);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParameterLocation {
+  Query,
+  Header,
+  Path,
+  Cookie,
+}
+
+fn parameter_key(parameter: &Parameter) -> ParameterKey {
+  match parameter {
+    Parameter::Query { parameter_data, .. } => {
+      ParameterKey(parameter_data.name.clone(), ParameterLocation::Query)
+    }
+    Parameter::Header { parameter_data, .. } => {
+      ParameterKey(parameter_data.name.clone(), ParameterLocation::Header)
+    }
+    Parameter::Path { parameter_data, .. } => {
+      ParameterKey(parameter_data.name.clone(), ParameterLocation::Path)
+    }
+    Parameter::Cookie { parameter_data, .. } => {
+      ParameterKey(parameter_data.name.clone(), ParameterLocation::Cookie)
+    }
+  }
+}
+
+/// Extracts the names of the `{...}` template variables in `path`, in
+/// the order they appear.
+fn template_variables(path: &str) -> Vec<String> {
+  split_path(path)
+    .into_iter()
+    .filter_map(|segment| match PathSegment::parse(segment) {
+      PathSegment::Variable(name) => Some(name),
+      PathSegment::Literal(_) => None,
+    })
+    .collect()
 }
 
 impl IntoIterator for Paths {
@@ -161,6 +489,153 @@ where
   ))
 }
 
+/// A single segment of a templated path, produced by splitting on `/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+  /// A literal path segment that must match exactly.
+  Literal(String),
+  /// A templated segment like `{id}`, whose matched value is captured.
+  Variable(String),
+}
+
+impl PathSegment {
+  /// Parses a single `/`-separated segment, recognizing `{...}` as a
+  /// variable segment and everything else as literal.
+  fn parse(segment: &str) -> PathSegment {
+    if segment.starts_with('{') && segment.ends_with('}') && segment.len() > 2 {
+      PathSegment::Variable(segment[1..segment.len() - 1].to_string())
+    } else {
+      PathSegment::Literal(segment.to_string())
+    }
+  }
+
+  /// Specificity rank used to pick between multiple matching templates:
+  /// literal segments (0) are preferred over variable segments (1).
+  fn specificity(&self) -> u8 {
+    match self {
+      PathSegment::Literal(_) => 0,
+      PathSegment::Variable(_) => 1,
+    }
+  }
+}
+
+/// Splits a path into its `/`-separated segments, normalizing away a
+/// leading and/or trailing slash so `/users/42` and `/users/42/` agree.
+fn split_path(path: &str) -> Vec<&str> {
+  path.trim_matches('/').split('/').collect()
+}
+
+/// Matches pre-split `template_segments` against a concrete request path's
+/// segments, returning the captured variable values on success.
+fn match_segments(
+  template_segments: &[PathSegment],
+  request_segments: &[&str],
+) -> Option<IndexMap<String, String>> {
+  if template_segments.len() != request_segments.len() {
+    return None;
+  }
+
+  let mut params = IndexMap::new();
+  for (template_segment, request_segment) in template_segments.iter().zip(request_segments) {
+    match template_segment {
+      PathSegment::Literal(literal) => {
+        if literal != request_segment {
+          return None;
+        }
+      }
+      PathSegment::Variable(name) => {
+        params.insert(name.clone(), (*request_segment).to_string());
+      }
+    }
+  }
+  Some(params)
+}
+
+/// The result of matching a concrete request path against a templated key
+/// in [Paths], returned by [Paths::match_path] and [PathRouter::match_path].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathMatch<'a> {
+  /// The templated path key that matched, e.g. `/users/{id}`.
+  pub template: &'a str,
+  /// The [PathItem] (or reference to one) registered under `template`.
+  pub item: &'a ReferenceOr<PathItem>,
+  /// Captured path parameter values, keyed by parameter name.
+  pub params: IndexMap<String, String>,
+}
+
+impl Paths {
+  /// Matches a concrete request path such as `/users/42/orders/7` against
+  /// the templated keys in this [Paths], returning the matching
+  /// [PathItem] and the captured path parameters.
+  ///
+  /// When more than one template could match the same path (for example
+  /// `/users/me` and `/users/{id}`), the template with the most literal
+  /// segments earlier wins.
+  ///
+  /// For repeated lookups against the same [Paths], prefer building a
+  /// [PathRouter] once instead of calling this on every request.
+  pub fn match_path(&self, request_path: &str) -> Option<PathMatch<'_>> {
+    let request_segments = split_path(request_path);
+
+    self
+      .paths
+      .iter()
+      .filter_map(|(template, item)| {
+        let template_segments: Vec<PathSegment> =
+          split_path(template).into_iter().map(PathSegment::parse).collect();
+        let params = match_segments(&template_segments, &request_segments)?;
+        let specificity: Vec<u8> = template_segments.iter().map(PathSegment::specificity).collect();
+        Some((specificity, template.as_str(), item, params))
+      })
+      .min_by(|a, b| a.0.cmp(&b.0))
+      .map(|(_, template, item, params)| PathMatch { template, item, params })
+  }
+}
+
+/// Pre-splits the templated keys of a [Paths] once, so repeated calls to
+/// [PathRouter::match_path] don't re-tokenize every template on every
+/// lookup.
+pub struct PathRouter<'a> {
+  paths: &'a Paths,
+  templates: Vec<(&'a str, Vec<PathSegment>)>,
+}
+
+impl<'a> PathRouter<'a> {
+  /// Builds a router over `paths`, splitting each templated key once.
+  pub fn new(paths: &'a Paths) -> Self {
+    let templates = paths
+      .paths
+      .keys()
+      .map(|template| {
+        let segments = split_path(template).into_iter().map(PathSegment::parse).collect();
+        (template.as_str(), segments)
+      })
+      .collect();
+    PathRouter { paths, templates }
+  }
+
+  /// Matches `request_path` against the precomputed templates. See
+  /// [Paths::match_path] for the matching and specificity rules.
+  pub fn match_path(&self, request_path: &str) -> Option<PathMatch<'a>> {
+    let request_segments = split_path(request_path);
+
+    self
+      .templates
+      .iter()
+      .filter_map(|(template, segments)| {
+        let params = match_segments(segments, &request_segments)?;
+        let specificity: Vec<u8> = segments.iter().map(PathSegment::specificity).collect();
+        Some((specificity, *template, params))
+      })
+      .min_by(|a, b| a.0.cmp(&b.0))
+      .map(|(_, template, params)| PathMatch {
+        template,
+        item: &self.paths.paths[template],
+        params,
+      })
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -190,4 +665,227 @@ mod tests {
     ];
     assert_eq!(path_item.into_iter().collect::<Vec<_>>(), expected);
   }
+
+  #[test]
+  fn test_method_from_str_and_display() {
+    assert_eq!("get".parse::<Method>().unwrap(), Method::Get);
+    assert_eq!("DELETE".parse::<Method>().unwrap(), Method::Delete);
+    assert_eq!(Method::Patch.to_string(), "patch");
+    assert!("nope".parse::<Method>().is_err());
+  }
+
+  #[test]
+  fn test_path_item_typed_operation_access() {
+    let mut path_item = PathItem::default();
+    assert_eq!(path_item.operation(Method::Get), None);
+
+    path_item.set_operation(Method::Get, Operation::default());
+    assert_eq!(path_item.operation(Method::Get), Some(&Operation::default()));
+    assert_eq!(path_item.operation(Method::Post), None);
+
+    path_item.operation_mut(Method::Get).unwrap().summary = Some("hi".to_string());
+    assert_eq!(path_item.operation(Method::Get).unwrap().summary, Some("hi".to_string()));
+
+    let taken = path_item.take_operation(Method::Get).unwrap();
+    assert_eq!(taken.summary, Some("hi".to_string()));
+    assert_eq!(path_item.operation(Method::Get), None);
+  }
+
+  #[test]
+  fn test_match_path_captures_variables() {
+    let mut paths = Paths::default();
+    paths.paths.insert(
+      "/users/{id}/orders/{orderId}".to_string(),
+      ReferenceOr::Item(PathItem::default()),
+    );
+
+    let result = paths.match_path("/users/42/orders/7").unwrap();
+    assert_eq!(result.template, "/users/{id}/orders/{orderId}");
+    assert_eq!(result.params.get("id").map(String::as_str), Some("42"));
+    assert_eq!(result.params.get("orderId").map(String::as_str), Some("7"));
+
+    assert!(paths.match_path("/users/42").is_none());
+  }
+
+  #[test]
+  fn test_match_path_prefers_more_specific_template() {
+    let mut paths = Paths::default();
+    paths.paths.insert("/users/{id}".to_string(), ReferenceOr::Item(PathItem::default()));
+    paths.paths.insert("/users/me".to_string(), ReferenceOr::Item(PathItem::default()));
+
+    let result = paths.match_path("/users/me").unwrap();
+    assert_eq!(result.template, "/users/me");
+
+    let result = paths.match_path("/users/42").unwrap();
+    assert_eq!(result.template, "/users/{id}");
+  }
+
+  #[test]
+  fn test_match_path_normalizes_trailing_slash() {
+    let mut paths = Paths::default();
+    paths.paths.insert("/users/{id}".to_string(), ReferenceOr::Item(PathItem::default()));
+
+    assert_eq!(paths.match_path("/users/42/").unwrap().template, "/users/{id}");
+    assert!(Paths::default().match_path("/users/42").is_none());
+  }
+
+  #[test]
+  fn test_path_router_matches_same_as_paths() {
+    let mut paths = Paths::default();
+    paths.paths.insert("/users/{id}".to_string(), ReferenceOr::Item(PathItem::default()));
+
+    let router = PathRouter::new(&paths);
+    let result = router.match_path("/users/42").unwrap();
+    assert_eq!(result.template, "/users/{id}");
+    assert_eq!(result.params.get("id").map(String::as_str), Some("42"));
+  }
+
+  fn path_parameter(name: &str, required: bool) -> ReferenceOr<Parameter> {
+    ReferenceOr::Item(Parameter::Path {
+      parameter_data: ParameterData {
+        name: name.to_string(),
+        description: None,
+        required,
+        deprecated: None,
+        format: ParameterSchemaOrContent::Schema(ReferenceOr::Item(Schema::default())),
+        example: None,
+        examples: IndexMap::new(),
+        explode: None,
+        extensions: IndexMap::new(),
+      },
+      style: PathStyle::Simple,
+    })
+  }
+
+  #[test]
+  fn test_validate_path_undeclared_variable() {
+    let path_item = PathItem::default();
+    let errors = path_item.validate_path("/users/{id}");
+    assert_eq!(
+      errors,
+      vec![PathValidationError::UndeclaredVariable {
+        path: "/users/{id}".to_string(),
+        name: "id".to_string(),
+      }]
+    );
+  }
+
+  #[test]
+  fn test_validate_path_unused_and_not_required_parameter() {
+    let path_item = PathItem {
+      parameters: vec![path_parameter("id", false), path_parameter("extra", true)],
+      ..Default::default()
+    };
+    let errors = path_item.validate_path("/users/{id}");
+    assert_eq!(
+      errors,
+      vec![
+        PathValidationError::NotRequired {
+          path: "/users/{id}".to_string(),
+          name: "id".to_string(),
+        },
+        PathValidationError::UnusedParameter {
+          path: "/users/{id}".to_string(),
+          name: "extra".to_string(),
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_validate_path_well_formed() {
+    let path_item = PathItem {
+      parameters: vec![path_parameter("id", true)],
+      ..Default::default()
+    };
+    assert_eq!(path_item.validate_path("/users/{id}"), Vec::new());
+  }
+
+  #[test]
+  fn test_effective_parameters_overrides_by_name_and_location() {
+    let overridden = path_parameter("id", true);
+    let unrelated = path_parameter("limit", false);
+    let path_item = PathItem {
+      parameters: vec![path_parameter("id", false), unrelated.clone()],
+      get: Some(Operation {
+        parameters: vec![overridden.clone()],
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let resolved = path_item.effective_parameters(Method::Get, |_| None);
+    assert_eq!(resolved, vec![&overridden, &unrelated]);
+  }
+
+  #[test]
+  fn test_effective_parameters_appends_new_operation_parameter() {
+    let path_param = path_parameter("id", true);
+    let new_param = path_parameter("verbose", false);
+    let path_item = PathItem {
+      parameters: vec![path_param.clone()],
+      get: Some(Operation {
+        parameters: vec![new_param.clone()],
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let resolved = path_item.effective_parameters(Method::Get, |_| None);
+    assert_eq!(resolved, vec![&path_param, &new_param]);
+  }
+
+  #[test]
+  fn test_iter_public_skips_x_internal_operations() {
+    let mut internal_extensions = IndexMap::new();
+    internal_extensions.insert("x-internal".to_string(), serde_json::Value::Bool(true));
+
+    let path_item = PathItem {
+      get: Some(Operation::default()),
+      post: Some(Operation {
+        extensions: internal_extensions,
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let methods: Vec<&str> = path_item.iter_public().map(|(method, _)| method).collect();
+    assert_eq!(methods, vec!["get"]);
+  }
+
+  #[test]
+  fn test_iter_public_with_key_honors_custom_extension() {
+    let mut beta_extensions = IndexMap::new();
+    beta_extensions.insert("x-beta".to_string(), serde_json::Value::Bool(true));
+
+    let path_item = PathItem {
+      get: Some(Operation::default()),
+      post: Some(Operation {
+        extensions: beta_extensions,
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let methods: Vec<&str> = path_item
+      .iter_public_with_key("x-beta")
+      .map(|(method, _)| method)
+      .collect();
+    assert_eq!(methods, vec!["get"]);
+  }
+
+  #[test]
+  fn test_iter_filtered_with_custom_predicate() {
+    let path_item = PathItem {
+      get: Some(Operation::default()),
+      post: Some(Operation::default()),
+      ..Default::default()
+    };
+
+    let methods: Vec<&str> = path_item
+      .iter_filtered(|method, _| method != "post")
+      .map(|(method, _)| method)
+      .collect();
+    assert_eq!(methods, vec!["get"]);
+  }
 }